@@ -0,0 +1,136 @@
+// Binary-to-text codecs used to dump genomes into logs or checkpoint files. Operates on plain
+// byte slices so it has no knowledge of `Genestring` itself; `lib.rs` layers the public
+// `to_base64`/`from_base64`/`to_hex`/`from_hex` methods on top of `to_be_bytes`/`from_be_bytes`.
+
+const STANDARD_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alphabet {
+    Standard,
+    UrlSafe,
+}
+
+impl Alphabet {
+    fn table(&self) -> &'static [u8; 64] {
+        match *self {
+            Alphabet::Standard => STANDARD_TABLE,
+            Alphabet::UrlSafe => URL_SAFE_TABLE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    InvalidLength,
+    InvalidCharacter(char),
+}
+
+// Encodes `bytes` to base64 text using the classic three-byte-to-four-character scheme,
+// padding the final group with `=` when `bytes.len()` isn't a multiple of three.
+pub fn encode_base64(bytes: &[u8], alphabet: Alphabet) -> String {
+    let table = alphabet.table();
+    let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+
+        let n = (b0 << 16) | (b1 << 8) | b2;
+
+        result.push(table[((n >> 18) & 0x3F) as usize] as char);
+        result.push(table[((n >> 12) & 0x3F) as usize] as char);
+        result.push(if chunk.len() > 1 {
+            table[((n >> 6) & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+        result.push(if chunk.len() > 2 {
+            table[(n & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    result
+}
+
+// Decodes base64 text produced by `encode_base64` back to bytes.
+pub fn decode_base64(text: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    let table = alphabet.table();
+    let chars = text.as_bytes();
+
+    if !chars.len().is_multiple_of(4) {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut result = Vec::with_capacity((chars.len() / 4) * 3);
+
+    for group in chars.chunks(4) {
+        let mut values = [0u32; 4];
+        let mut padding = 0;
+
+        for (i, &c) in group.iter().enumerate() {
+            if c == b'=' {
+                padding += 1;
+            } else {
+                let position = table
+                    .iter()
+                    .position(|&t| t == c)
+                    .ok_or(DecodeError::InvalidCharacter(c as char))?;
+                values[i] = position as u32;
+            }
+        }
+
+        let n = (values[0] << 18) | (values[1] << 12) | (values[2] << 6) | values[3];
+
+        result.push((n >> 16) as u8);
+        if padding < 2 {
+            result.push((n >> 8) as u8);
+        }
+        if padding < 1 {
+            result.push(n as u8);
+        }
+    }
+
+    Ok(result)
+}
+
+// Encodes `bytes` as lowercase hex, two characters per byte.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        result.push_str(&format!("{:02x}", byte));
+    }
+    result
+}
+
+// Decodes lowercase or uppercase hex text back to bytes.
+pub fn decode_hex(text: &str) -> Result<Vec<u8>, DecodeError> {
+    let chars = text.as_bytes();
+
+    if !chars.len().is_multiple_of(2) {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut result = Vec::with_capacity(chars.len() / 2);
+    for pair in chars.chunks(2) {
+        let hi = hex_digit(pair[0])?;
+        let lo = hex_digit(pair[1])?;
+        result.push((hi << 4) | lo);
+    }
+
+    Ok(result)
+}
+
+fn hex_digit(c: u8) -> Result<u8, DecodeError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(DecodeError::InvalidCharacter(c as char)),
+    }
+}
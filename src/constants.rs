@@ -0,0 +1,5 @@
+// Number of bits held by a single piece (a `u64` limb) of a genestring.
+pub const PIECE_SIZE_IN_BITS: u64 = 64;
+
+// Number of bytes held by a single piece (a `u64` limb) of a genestring.
+pub const PIECE_SIZE_IN_BYTES: u64 = 8;
@@ -2,9 +2,11 @@
 #[macro_use]
 extern crate proptest;
 
+pub mod codec;
 pub mod constants;
 pub mod math;
 
+use codec::DecodeError;
 use constants::*;
 use math::*;
 
@@ -44,149 +46,362 @@ impl Genestring {
         self.pieces.is_empty()
     }
 
+    // Retrieves `bits` number of bits from the string, starting at a given `offset`, without
+    // panicking if `bits`/`offset` go out of bounds.
+    pub fn try_get(&self, offset: u64, bits: u64) -> Result<u64, OutOfBounds> {
+        try_get_bits(&self.pieces, offset, bits)
+    }
+
     // Retrieves `bits` number of bits from the string, starting at a given `offset`. Panics if
     // `bits` is larger than 64 or would otherwise go outside the bounds of the string.
     pub fn get(&self, offset: u64, bits: u64) -> u64 {
-        if bits == 0 {
-            return 0;
+        match self.try_get(offset, bits) {
+            Ok(value) => value,
+            Err(OutOfBounds::TooManyBits) => panic!("Can only obtain 64 bits at a time!"),
+            Err(OutOfBounds::OutOfRange) => panic!("{}", PANIC_OUT_OF_BOUNDS),
         }
+    }
 
-        // safety dance
-        if bits > 64 {
-            panic!("Can only obtain 64 bits at a time!");
+    // Fills each piece of the genestring from a supplied fill function.
+    // The assumed usage of this function is for inserting random values for new DNA.
+    pub fn fill<F>(&mut self, mut filler: F)
+    where
+        F: FnMut() -> u64,
+    {
+        for i in self.pieces.iter_mut() {
+            *i = filler();
         }
+    }
+
+    // Assigns bits at a given offset through offset+bits to the given value, without
+    // panicking if `bits`/`offset` go out of bounds.
+    pub fn try_set(&mut self, offset: u64, bits: u64, value: u64) -> Result<(), OutOfBounds> {
+        try_set_bits(&mut self.pieces, offset, bits, value)
+    }
 
-        if bits + offset > self.bit_len() as u64 {
-            panic!(PANIC_OUT_OF_BOUNDS);
+    // Assigns bits at a given offset through offset+bits to the given value.
+    // The assumed usage of this function is to implement mutation.
+    pub fn set(&mut self, offset: u64, bits: u64, value: u64) {
+        match self.try_set(offset, bits, value) {
+            Ok(()) => {}
+            Err(OutOfBounds::TooManyBits) => panic!("Can only write 64 bits at a time!"),
+            Err(OutOfBounds::OutOfRange) => panic!("{}", PANIC_OUT_OF_BOUNDS),
         }
+    }
 
-        // safety dance complete, now figure out which pieces have our bits
-        let first_half_idx = part_for_bit(offset) as usize;
-        let second_half_idx = part_for_bit(offset + (bits - 1)) as usize;
+    // Copies bits starting from a given offset, up to offset+bits, from a donor genestring to this one.
+    // The assumed usage of this function is to implement crossover between generations.
+    pub fn transplant(&mut self, donor: &Genestring, offset: u64, bits: u64) {
+        let end = bits + offset;
 
-        let offset_modulo = offset % PIECE_SIZE_IN_BITS;
+        if end > self.bit_len() as u64 || end > donor.bit_len() as u64 {
+            panic!("{}", PANIC_OUT_OF_BOUNDS);
+        }
 
-        let mut result: u64 = 0;
+        if bits <= 64 {
+            self.set(offset, bits, donor.get(offset, bits));
+        } else {
+            let mut offset = offset;
+            let bit_windows = bits / PIECE_SIZE_IN_BITS;
+            for _ in 0..bit_windows {
+                self.set(
+                    offset,
+                    PIECE_SIZE_IN_BITS,
+                    donor.get(offset, PIECE_SIZE_IN_BITS),
+                );
+                offset += PIECE_SIZE_IN_BITS;
+            }
+            self.set(
+                offset,
+                bits % PIECE_SIZE_IN_BITS,
+                donor.get(offset, bits % PIECE_SIZE_IN_BITS),
+            );
+        }
+    }
 
-        if first_half_idx != second_half_idx {
-            // accumulator
-            let mut acc: u64 = 0;
+    // Treats `self` and `other` as little-endian magnitudes (piece 0 holds the low bits) and
+    // adds them, limb by limb, with carry propagation. The result is sized to the longer of
+    // the two operands; missing high limbs of the shorter one are treated as zero. Returns
+    // the carry bit instead of panicking on overflow.
+    pub fn overflowing_add(&self, other: &Genestring) -> (Genestring, bool) {
+        let mut result = Genestring::with_bits(self.len().max(other.len()) as u64 * PIECE_SIZE_IN_BITS);
+        let carry = overflowing_add(&self.pieces, &other.pieces, &mut result.pieces);
+        (result, carry)
+    }
 
-            // calculate bit mask to use against value for first part
-            let p1_bits = PIECE_SIZE_IN_BITS - offset_modulo;
-            for i in 0..p1_bits {
-                acc += 1 << i;
-            }
-            let value_mask1 = acc;
+    // Subtracts `other` from `self` as little-endian magnitudes, limb by limb. Returns the
+    // borrow bit instead of panicking on underflow.
+    pub fn overflowing_sub(&self, other: &Genestring) -> (Genestring, bool) {
+        let mut result = Genestring::with_bits(self.len().max(other.len()) as u64 * PIECE_SIZE_IN_BITS);
+        let borrow = overflowing_sub(&self.pieces, &other.pieces, &mut result.pieces);
+        (result, borrow)
+    }
 
-            // calculate bit mask to use against value for second part
-            let p2_bits = bits - p1_bits;
-            acc = 0;
-            for i in 0..p2_bits {
-                acc += 1 << i;
-            }
-            let piece_mask2 = acc;
+    // Shifts the whole genestring left by `bits`, returning a new genestring the same size as
+    // `self`. Bits shifted past the top are discarded.
+    pub fn shl(&self, bits: u64) -> Genestring {
+        let mut result = Genestring::with_bits(self.bit_len() as u64);
+        shl_limbs(&self.pieces, bits, &mut result.pieces);
+        result
+    }
 
-            let piece_mask1 = value_mask1 << offset_modulo;
+    // Shifts the whole genestring right by `bits`, returning a new genestring the same size as
+    // `self`. Bits shifted past the bottom are discarded.
+    pub fn shr(&self, bits: u64) -> Genestring {
+        let mut result = Genestring::with_bits(self.bit_len() as u64);
+        shr_limbs(&self.pieces, bits, &mut result.pieces);
+        result
+    }
 
-            result = (self.pieces[first_half_idx] & piece_mask1) >> offset_modulo;
-            result += (self.pieces[second_half_idx] & piece_mask2) << p1_bits;
-        } else {
-            let first_half = self.pieces[first_half_idx];
+    // Returns the pieces backing this genestring as a flat slice of limbs, for zero-copy
+    // interop with other `u64`-limb libraries.
+    pub fn limbs(&self) -> &[u64] {
+        &self.pieces
+    }
 
-            let piece = first_half;
-            for i in offset_modulo..(offset_modulo + bits) {
-                let mask = 1 << i;
-                result += piece & mask;
-            }
+    // Mutable counterpart to `limbs`.
+    pub fn limbs_mut(&mut self) -> &mut [u64] {
+        &mut self.pieces
+    }
 
-            result >>= offset_modulo;
+    // Serializes the genestring to big-endian bytes, most significant piece first, each piece
+    // itself written most significant byte first.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.byte_len());
+        for &piece in self.pieces.iter().rev() {
+            result.extend_from_slice(&piece.to_be_bytes());
         }
-
         result
     }
 
-    // Fills each piece of the genestring from a supplied fill function.
-    // The assumed usage of this function is for inserting random values for new DNA.
-    pub fn fill<F>(&mut self, mut filler: F)
-    where
-        F: FnMut() -> u64,
-    {
-        for i in self.pieces.iter_mut() {
-            *i = filler();
+    // Reconstructs a genestring from a big-endian byte buffer, as produced by `to_be_bytes`.
+    // The piece count is chosen from the byte length via `part_count_for_bits`, so a leading
+    // (i.e. most significant) partial word is zero-padded rather than rejected. This means
+    // `to_be_bytes`∘`from_be_bytes` is only an identity for buffers whose length is already a
+    // multiple of `PIECE_SIZE_IN_BYTES`; for any other length, the result is the input
+    // left-padded with zero bytes up to the next whole piece. An empty buffer yields a single
+    // zero piece, since `part_count_for_bits(0)` is 1. Genestring is unbounded, so this
+    // never actually fails, unlike the `DecodeError`-returning text codecs built on top of it.
+    pub fn from_be_bytes(bytes: &[u8]) -> Genestring {
+        let piece_count = part_count_for_bits(bytes.len() as u64 * 8) as usize;
+        let mut pieces = vec![0u64; piece_count];
+
+        for (i, &byte) in bytes.iter().rev().enumerate() {
+            let piece_idx = i / PIECE_SIZE_IN_BYTES as usize;
+            let shift = (i % PIECE_SIZE_IN_BYTES as usize) * 8;
+            pieces[piece_idx] |= (byte as u64) << shift;
         }
+
+        Genestring { pieces }
     }
 
-    // Assigns bits at a given offset through offset+bits to the given value.
-    // The assumed usage of this function is to implement mutation.
-    pub fn set(&mut self, offset: u64, bits: u64, value: u64) {
-        if bits == 0 {
-            return;
+    // Serializes the genestring to little-endian bytes, least significant piece first, each
+    // piece itself written least significant byte first.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.byte_len());
+        for &piece in self.pieces.iter() {
+            result.extend_from_slice(&piece.to_le_bytes());
         }
+        result
+    }
 
-        // safety dance
-        if bits > 64 {
-            panic!("Can only write 64 bits at a time!");
+    // Reconstructs a genestring from a little-endian byte buffer, as produced by
+    // `to_le_bytes`. A trailing (i.e. most significant) partial word is zero-padded rather than
+    // rejected, so `to_le_bytes`∘`from_le_bytes` is only an identity for buffers whose length is
+    // already a multiple of `PIECE_SIZE_IN_BYTES`; for any other length, the result is the input
+    // right-padded with zero bytes up to the next whole piece. As with `from_be_bytes`, an
+    // empty buffer yields a single zero piece. See `from_be_bytes` for why this never fails.
+    pub fn from_le_bytes(bytes: &[u8]) -> Genestring {
+        let piece_count = part_count_for_bits(bytes.len() as u64 * 8) as usize;
+        let mut pieces = vec![0u64; piece_count];
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            let piece_idx = i / PIECE_SIZE_IN_BYTES as usize;
+            let shift = (i % PIECE_SIZE_IN_BYTES as usize) * 8;
+            pieces[piece_idx] |= (byte as u64) << shift;
         }
 
-        if bits + offset > self.bit_len() as u64 {
-            panic!(PANIC_OUT_OF_BOUNDS);
-        }
+        Genestring { pieces }
+    }
 
-        let first_half_idx = part_for_bit(offset) as usize;
-        let second_half_idx = part_for_bit(offset + (bits - 1)) as usize;
+    // Encodes the genestring as standard-alphabet base64 text, suitable for dropping into
+    // logs or config files. Built on top of `to_be_bytes`, so the text form is just an
+    // encoding of the big-endian bytes.
+    pub fn to_base64(&self) -> String {
+        codec::encode_base64(&self.to_be_bytes(), codec::Alphabet::Standard)
+    }
 
-        let mut source_mask = 0;
+    // Same as `to_base64`, but using the URL/filename-safe alphabet (`-`/`_` in place of
+    // `+`/`/`).
+    pub fn to_base64_url(&self) -> String {
+        codec::encode_base64(&self.to_be_bytes(), codec::Alphabet::UrlSafe)
+    }
 
-        let offset_modulo = offset % PIECE_SIZE_IN_BITS;
+    // Decodes standard-alphabet base64 text produced by `to_base64` back into a genestring.
+    pub fn from_base64(text: &str) -> Result<Genestring, DecodeError> {
+        let bytes = codec::decode_base64(text, codec::Alphabet::Standard)?;
+        Ok(Genestring::from_be_bytes(&bytes))
+    }
 
-        if first_half_idx == second_half_idx {
-            // in this path, we are just writing to bits inside the same integer
-            for i in 0..bits {
-                source_mask += 1 << i;
-            }
+    // Decodes URL/filename-safe base64 text produced by `to_base64_url` back into a
+    // genestring.
+    pub fn from_base64_url(text: &str) -> Result<Genestring, DecodeError> {
+        let bytes = codec::decode_base64(text, codec::Alphabet::UrlSafe)?;
+        Ok(Genestring::from_be_bytes(&bytes))
+    }
 
-            let destination_mask = source_mask << offset_modulo;
+    // Encodes the genestring as lowercase hex text.
+    pub fn to_hex(&self) -> String {
+        codec::encode_hex(&self.to_be_bytes())
+    }
 
-            self.pieces[first_half_idx] = (self.pieces[first_half_idx] & !destination_mask)
-                + ((value as u64 & source_mask) << offset_modulo);
-        } else {
-            // accumulator
-            let mut acc: u64 = 0;
+    // Decodes hex text produced by `to_hex` back into a genestring.
+    pub fn from_hex(text: &str) -> Result<Genestring, DecodeError> {
+        let bytes = codec::decode_hex(text)?;
+        Ok(Genestring::from_be_bytes(&bytes))
+    }
 
-            // calculate bit mask to use against value for first part
-            let p1_bits = PIECE_SIZE_IN_BITS - offset_modulo;
-            for i in 0..p1_bits {
-                acc += 1 << i;
-            }
-            let value_mask1 = acc;
+    // Reads a Gray-coded field back to its binary value. Fields written with `set_gray` avoid
+    // the Hamming cliff that plain binary fields suffer from: adjacent integers are stored as
+    // codes that differ in exactly one bit, so stepping the value by one never needs many
+    // stored bits to flip at once. A single flipped stored bit can still move the decoded
+    // value by more than one step (flipping the top bit of a 3-bit code `000` decodes to 7).
+    pub fn get_gray(&self, offset: u64, bits: u64) -> u64 {
+        gray_decode(self.get(offset, bits), bits)
+    }
 
-            // calculate bit mask to use against value for second part
-            let p2_bits = bits - p1_bits;
-            acc = 0;
-            for i in 0..p2_bits {
-                acc += 1 << i;
-            }
-            let piece_mask2 = acc;
-            acc <<= p1_bits;
-            let value_mask2 = acc;
+    // Encodes `value` to Gray code before storing it at `offset`. `value` is masked down to
+    // `bits` first, so bits above the field width are irrelevant, matching `set`'s contract.
+    // The mask is skipped when `bits` is out of range so that `set` still reports it via its
+    // usual `TooManyBits` panic instead of `bit_mask` panicking on the shift first.
+    pub fn set_gray(&mut self, offset: u64, bits: u64, value: u64) {
+        let masked = if bits <= PIECE_SIZE_IN_BITS { value & bit_mask(bits) } else { value };
+        self.set(offset, bits, gray_encode(masked));
+    }
+}
+
+// Compares genestrings as little-endian magnitudes, from the most significant limb down.
+// Genestrings with differing piece counts compare as if the shorter one were zero-padded at
+// the top.
+impl Ord for Genestring {
+    fn cmp(&self, other: &Genestring) -> std::cmp::Ordering {
+        cmp_limbs(&self.pieces, &other.pieces)
+    }
+}
+
+impl PartialOrd for Genestring {
+    fn partial_cmp(&self, other: &Genestring) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Genestring {
+    fn eq(&self, other: &Genestring) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Genestring {}
+
+// Type alias helpers for picking `N` the same way the rest of this module picks piece
+// counts: by bit width divided by the piece size, rounded up.
+pub type FixedGenestring64 = FixedGenestring<{ math::const_part_count_for_bits(64) }>;
+pub type FixedGenestring128 = FixedGenestring<{ math::const_part_count_for_bits(128) }>;
+pub type FixedGenestring256 = FixedGenestring<{ math::const_part_count_for_bits(256) }>;
+
+// A genestring whose pieces live inline in a `[u64; N]` rather than a `Vec<u64>`. Use this
+// when the bit count is known up front and you want genomes on the stack (or packed inline
+// into an array of individuals) instead of paying for a heap allocation per genome.
+//
+// `N` is a piece count, not a bit count; `const_part_count_for_bits` converts between the
+// two the same way `part_count_for_bits` does for the `Vec`-backed `Genestring`.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedGenestring<const N: usize> {
+    pieces: [u64; N],
+}
+
+impl<const N: usize> Default for FixedGenestring<N> {
+    fn default() -> Self {
+        FixedGenestring { pieces: [0u64; N] }
+    }
+}
+
+impl<const N: usize> FixedGenestring<N> {
+    // Creates a gene string with all pieces zeroed. `N` fixes the bit count at compile time,
+    // so unlike `Genestring::with_bits` there is nothing to allocate.
+    pub fn from_bits() -> FixedGenestring<N> {
+        FixedGenestring::default()
+    }
+
+    // Returns the number of bits in the gene string.
+    pub fn bit_len(&self) -> usize {
+        N * PIECE_SIZE_IN_BITS as usize
+    }
 
-            let piece_mask1 = value_mask1 << offset_modulo;
+    // Returns the number of bytes in the gene string.
+    pub fn byte_len(&self) -> usize {
+        N * PIECE_SIZE_IN_BYTES as usize
+    }
+
+    // Returns the number of integer parts of the gene string.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    // Retrieves `bits` number of bits from the string, starting at a given `offset`, without
+    // panicking if `bits`/`offset` go out of bounds.
+    pub fn try_get(&self, offset: u64, bits: u64) -> Result<u64, OutOfBounds> {
+        try_get_bits(&self.pieces, offset, bits)
+    }
+
+    // Retrieves `bits` number of bits from the string, starting at a given `offset`. Panics if
+    // `bits` is larger than 64 or would otherwise go outside the bounds of the string.
+    pub fn get(&self, offset: u64, bits: u64) -> u64 {
+        match self.try_get(offset, bits) {
+            Ok(value) => value,
+            Err(OutOfBounds::TooManyBits) => panic!("Can only obtain 64 bits at a time!"),
+            Err(OutOfBounds::OutOfRange) => panic!("{}", PANIC_OUT_OF_BOUNDS),
+        }
+    }
 
-            self.pieces[first_half_idx] = (self.pieces[first_half_idx] & !piece_mask1)
-                + ((value & value_mask1) << offset_modulo);
-            self.pieces[second_half_idx] =
-                (self.pieces[second_half_idx] & !piece_mask2) + ((value & value_mask2) >> p1_bits);
+    // Fills each piece of the genestring from a supplied fill function.
+    // The assumed usage of this function is for inserting random values for new DNA.
+    pub fn fill<F>(&mut self, mut filler: F)
+    where
+        F: FnMut() -> u64,
+    {
+        for i in self.pieces.iter_mut() {
+            *i = filler();
+        }
+    }
+
+    // Assigns bits at a given offset through offset+bits to the given value, without
+    // panicking if `bits`/`offset` go out of bounds.
+    pub fn try_set(&mut self, offset: u64, bits: u64, value: u64) -> Result<(), OutOfBounds> {
+        try_set_bits(&mut self.pieces, offset, bits, value)
+    }
+
+    // Assigns bits at a given offset through offset+bits to the given value.
+    // The assumed usage of this function is to implement mutation.
+    pub fn set(&mut self, offset: u64, bits: u64, value: u64) {
+        match self.try_set(offset, bits, value) {
+            Ok(()) => {}
+            Err(OutOfBounds::TooManyBits) => panic!("Can only write 64 bits at a time!"),
+            Err(OutOfBounds::OutOfRange) => panic!("{}", PANIC_OUT_OF_BOUNDS),
         }
     }
 
     // Copies bits starting from a given offset, up to offset+bits, from a donor genestring to this one.
     // The assumed usage of this function is to implement crossover between generations.
-    pub fn transplant(&mut self, donor: &Genestring, offset: u64, bits: u64) {
+    pub fn transplant(&mut self, donor: &FixedGenestring<N>, offset: u64, bits: u64) {
         let end = bits + offset;
 
         if end > self.bit_len() as u64 || end > donor.bit_len() as u64 {
-            panic!(PANIC_OUT_OF_BOUNDS);
+            panic!("{}", PANIC_OUT_OF_BOUNDS);
         }
 
         if bits <= 64 {
@@ -209,6 +424,17 @@ impl Genestring {
             );
         }
     }
+
+    // Reads a Gray-coded field back to its binary value. See `Genestring::get_gray`.
+    pub fn get_gray(&self, offset: u64, bits: u64) -> u64 {
+        gray_decode(self.get(offset, bits), bits)
+    }
+
+    // Encodes `value` to Gray code before storing it at `offset`. See `Genestring::set_gray`.
+    pub fn set_gray(&mut self, offset: u64, bits: u64, value: u64) {
+        let masked = if bits <= PIECE_SIZE_IN_BITS { value & bit_mask(bits) } else { value };
+        self.set(offset, bits, gray_encode(masked));
+    }
 }
 
 #[cfg(test)]
@@ -271,6 +497,67 @@ mod tests {
         assert_eq!(gs.get(60, 8), 0xFF);
     }
 
+    #[test]
+    fn fixed_get_set_same_chunk() {
+        assert_eq!(PIECE_SIZE_IN_BITS, 64);
+        let mut gs: FixedGenestring64 = FixedGenestring::from_bits();
+
+        eprintln!("{:?}", gs);
+        gs.set(8, 12, 842);
+        eprintln!("{:?}", gs);
+        assert_eq!(gs.get(8, 12), 842);
+    }
+
+    #[test]
+    fn fixed_get_set_different_chunk() {
+        assert_eq!(PIECE_SIZE_IN_BITS, 64);
+        let mut gs: FixedGenestring128 = FixedGenestring::from_bits();
+
+        eprintln!("{:?}", gs);
+        gs.set(60, 8, 0xFF);
+        eprintln!("{:?}", gs);
+        assert_eq!(gs.pieces[0], 0xF000000000000000);
+        assert_eq!(gs.pieces[1], 0x000000000000000F);
+        assert_eq!(gs.get(60, 8), 0xFF);
+    }
+
+    #[test]
+    fn fixed_transplant() {
+        assert_eq!(PIECE_SIZE_IN_BITS, 64);
+        let mut gs: FixedGenestring128 = FixedGenestring::from_bits();
+        let mut gs2: FixedGenestring128 = FixedGenestring::from_bits();
+
+        gs.set(4, 16, 1234);
+        gs.set(96, 16, 5678);
+
+        gs2.transplant(&gs, 0, 128);
+
+        assert_eq!(gs2.get(4, 16), 1234);
+        assert_eq!(gs2.get(96, 16), 5678);
+    }
+
+    #[test]
+    fn fixed_fill() {
+        let mut gs: FixedGenestring128 = FixedGenestring::from_bits();
+        let mut counter = 0u64;
+        gs.fill(|| {
+            counter += 1;
+            counter
+        });
+        assert_eq!(gs.pieces[0], 1);
+        assert_eq!(gs.pieces[1], 2);
+    }
+
+    #[test]
+    fn gray_set_masks_high_bits() {
+        // Bits above the field width must be irrelevant to `set_gray`, exactly as they are
+        // for `set`: encoding an out-of-range value should behave identically to encoding the
+        // value already masked down to `bits`.
+        let mut gs = Genestring::with_bits(64);
+        gs.set_gray(0, 4, 31);
+        assert_eq!(gs.get_gray(0, 4), 31 & 0xF);
+    }
+
     #[test]
     fn string_size_minimum() {
         // just making sure this bit of math works as we expect it to
@@ -278,6 +565,29 @@ mod tests {
         assert_eq!(part_count_for_bits(0), 1);
     }
 
+    #[test]
+    fn try_get_set_out_of_range() {
+        let mut gs = Genestring::with_bits(64);
+        assert_eq!(gs.try_get(60, 8), Err(OutOfBounds::OutOfRange));
+        assert_eq!(gs.try_set(60, 8, 1), Err(OutOfBounds::OutOfRange));
+    }
+
+    #[test]
+    fn try_get_set_too_many_bits() {
+        let mut gs = Genestring::with_bits(64);
+        assert_eq!(gs.try_get(0, 65), Err(OutOfBounds::TooManyBits));
+        assert_eq!(gs.try_set(0, 65, 1), Err(OutOfBounds::TooManyBits));
+    }
+
+    #[test]
+    fn try_get_set_matches_panicking_variants() {
+        let mut gs = Genestring::with_bits(128);
+        assert_eq!(gs.try_set(60, 8, 0xFF), Ok(()));
+        gs.set(68, 8, 0xAA);
+        assert_eq!(gs.try_get(60, 8), Ok(0xFF));
+        assert_eq!(gs.try_get(68, 8), Ok(0xAA));
+    }
+
     // proptest does some more intensive checks to ensure things like split numbers always work
     // or we don't trample non-overlapping numbers doing arithmetic.
 
@@ -347,6 +657,21 @@ mod tests {
             prop_assert_eq!(gs.get(b as u64, 16), value_b as u64);
         }
 
+        #[test]
+        fn fixed_get_set_multibinning(a in 0..16, b in 32..100, value_a: u16, value_b: u16) {
+            // Same coverage as `get_set_multibinning`, but against the array-backed
+            // `FixedGenestring`, which shares the bit-addressing math but stores its pieces in
+            // a fixed-size array instead of a `Vec`.
+            assert_eq!(PIECE_SIZE_IN_BITS, 64);
+            let mut gs: FixedGenestring128 = FixedGenestring::from_bits();
+
+            gs.set(a as u64, 16, value_a as u64);
+            gs.set(b as u64, 16, value_b as u64);
+
+            prop_assert_eq!(gs.get(a as u64, 16), value_a as u64);
+            prop_assert_eq!(gs.get(b as u64, 16), value_b as u64);
+        }
+
         #[test]
         fn transplanting_small_ranges(a in 0..32, b in 64..100, value_a: u16, value_b: u16) {
             assert_eq!(PIECE_SIZE_IN_BITS, 64);
@@ -404,5 +729,223 @@ mod tests {
             prop_assert_eq!(gs2.get(a as u64 * 16, 16), value_a as u64);
             prop_assert_eq!(gs2.get(b as u64 * 16, 16), value_b as u64);
         }
+
+        #[test]
+        fn bigint_add_matches_u128(a: u64, b: u64, c: u64, d: u64) {
+            // (a, c) and (b, d) are the low and high halves of two little-endian 128-bit
+            // magnitudes; our limb-wise add should agree with native u128 arithmetic.
+            let mut gs_a = Genestring::with_bits(128);
+            gs_a.set(0, 64, a);
+            gs_a.set(64, 64, c);
+
+            let mut gs_b = Genestring::with_bits(128);
+            gs_b.set(0, 64, b);
+            gs_b.set(64, 64, d);
+
+            let lhs = ((c as u128) << 64) | a as u128;
+            let rhs = ((d as u128) << 64) | b as u128;
+            let (expected, expected_carry) = lhs.overflowing_add(rhs);
+
+            let (sum, carry) = gs_a.overflowing_add(&gs_b);
+
+            prop_assert_eq!(carry, expected_carry);
+            prop_assert_eq!(sum.get(0, 64), expected as u64);
+            prop_assert_eq!(sum.get(64, 64), (expected >> 64) as u64);
+        }
+
+        #[test]
+        fn bigint_sub_matches_u128(a: u64, b: u64, c: u64, d: u64) {
+            let mut gs_a = Genestring::with_bits(128);
+            gs_a.set(0, 64, a);
+            gs_a.set(64, 64, c);
+
+            let mut gs_b = Genestring::with_bits(128);
+            gs_b.set(0, 64, b);
+            gs_b.set(64, 64, d);
+
+            let lhs = ((c as u128) << 64) | a as u128;
+            let rhs = ((d as u128) << 64) | b as u128;
+            let (expected, expected_borrow) = lhs.overflowing_sub(rhs);
+
+            let (diff, borrow) = gs_a.overflowing_sub(&gs_b);
+
+            prop_assert_eq!(borrow, expected_borrow);
+            prop_assert_eq!(diff.get(0, 64), expected as u64);
+            prop_assert_eq!(diff.get(64, 64), (expected >> 64) as u64);
+        }
+
+        #[test]
+        fn bigint_cmp_matches_u64(a: u64, b: u64) {
+            let mut gs_a = Genestring::with_bits(64);
+            gs_a.set(0, 64, a);
+
+            let mut gs_b = Genestring::with_bits(64);
+            gs_b.set(0, 64, b);
+
+            prop_assert_eq!(gs_a.cmp(&gs_b), a.cmp(&b));
+        }
+
+        #[test]
+        fn bigint_add_cmp_differing_piece_counts(a: u64, b: u64, c: u64) {
+            // `a` alone is a 64-bit (one piece) magnitude; `(b, c)` is a 128-bit (two piece)
+            // magnitude. Addition and comparison must both treat the shorter operand's missing
+            // high limb as zero rather than panicking or truncating the longer one.
+            let mut gs_a = Genestring::with_bits(64);
+            gs_a.set(0, 64, a);
+
+            let mut gs_b = Genestring::with_bits(128);
+            gs_b.set(0, 64, b);
+            gs_b.set(64, 64, c);
+
+            let lhs = a as u128;
+            let rhs = ((c as u128) << 64) | b as u128;
+
+            let (expected, expected_carry) = lhs.overflowing_add(rhs);
+            let (sum, carry) = gs_a.overflowing_add(&gs_b);
+
+            prop_assert_eq!(carry, expected_carry);
+            prop_assert_eq!(sum.get(0, 64), expected as u64);
+            prop_assert_eq!(sum.get(64, 64), (expected >> 64) as u64);
+
+            prop_assert_eq!(gs_a.cmp(&gs_b), lhs.cmp(&rhs));
+        }
+
+        #[test]
+        fn bigint_shift_roundtrip(value: u64, shift in 0..64u32) {
+            // Shifting a 64-bit value left within a 128-bit genestring never loses bits, so
+            // shifting it back right by the same amount must recover the original value.
+            let mut gs = Genestring::with_bits(128);
+            gs.set(0, 64, value);
+
+            let shifted = gs.shl(shift as u64);
+            let back = shifted.shr(shift as u64);
+
+            prop_assert_eq!(back.get(0, 64), value);
+        }
+
+        #[test]
+        fn be_bytes_roundtrip(values in proptest::collection::vec(proptest::prelude::any::<u64>(), 1..8)) {
+            // Piece-aligned buffers (built from whole u64 values) round-trip exactly.
+            let mut gs = Genestring::with_bits(values.len() as u64 * PIECE_SIZE_IN_BITS);
+            for (i, value) in values.iter().enumerate() {
+                gs.set(i as u64 * PIECE_SIZE_IN_BITS, PIECE_SIZE_IN_BITS, *value);
+            }
+
+            let bytes = gs.to_be_bytes();
+            let back = Genestring::from_be_bytes(&bytes);
+
+            prop_assert_eq!(gs.pieces, back.pieces);
+        }
+
+        #[test]
+        fn be_bytes_roundtrip_unaligned(bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 1..40)) {
+            // For a buffer whose length isn't a multiple of `PIECE_SIZE_IN_BYTES`,
+            // `from_be_bytes`.`to_be_bytes` is not identity: it's the input left-padded with
+            // zero bytes up to the next whole piece.
+            let back = Genestring::from_be_bytes(&bytes);
+            let round_tripped = back.to_be_bytes();
+
+            let pad = round_tripped.len() - bytes.len();
+            prop_assert!(round_tripped[..pad].iter().all(|&b| b == 0));
+            prop_assert_eq!(&round_tripped[pad..], &bytes[..]);
+        }
+
+        #[test]
+        fn le_bytes_roundtrip(values in proptest::collection::vec(proptest::prelude::any::<u64>(), 1..8)) {
+            // Piece-aligned buffers (built from whole u64 values) round-trip exactly.
+            let mut gs = Genestring::with_bits(values.len() as u64 * PIECE_SIZE_IN_BITS);
+            for (i, value) in values.iter().enumerate() {
+                gs.set(i as u64 * PIECE_SIZE_IN_BITS, PIECE_SIZE_IN_BITS, *value);
+            }
+
+            let bytes = gs.to_le_bytes();
+            let back = Genestring::from_le_bytes(&bytes);
+
+            prop_assert_eq!(gs.pieces, back.pieces);
+        }
+
+        #[test]
+        fn le_bytes_roundtrip_unaligned(bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 1..40)) {
+            // For a buffer whose length isn't a multiple of `PIECE_SIZE_IN_BYTES`,
+            // `from_le_bytes`.`to_le_bytes` is not identity: it's the input right-padded with
+            // zero bytes up to the next whole piece.
+            let back = Genestring::from_le_bytes(&bytes);
+            let round_tripped = back.to_le_bytes();
+
+            prop_assert_eq!(&round_tripped[..bytes.len()], &bytes[..]);
+            prop_assert!(round_tripped[bytes.len()..].iter().all(|&b| b == 0));
+        }
+
+        #[test]
+        fn base64_roundtrip(values in proptest::collection::vec(proptest::prelude::any::<u64>(), 1..8)) {
+            let mut gs = Genestring::with_bits(values.len() as u64 * PIECE_SIZE_IN_BITS);
+            for (i, value) in values.iter().enumerate() {
+                gs.set(i as u64 * PIECE_SIZE_IN_BITS, PIECE_SIZE_IN_BITS, *value);
+            }
+
+            let encoded = gs.to_base64();
+            let back = Genestring::from_base64(&encoded).unwrap();
+            prop_assert_eq!(&gs.pieces, &back.pieces);
+
+            let encoded_url = gs.to_base64_url();
+            let back_url = Genestring::from_base64_url(&encoded_url).unwrap();
+            prop_assert_eq!(gs.pieces, back_url.pieces);
+        }
+
+        #[test]
+        fn hex_roundtrip(values in proptest::collection::vec(proptest::prelude::any::<u64>(), 1..8)) {
+            let mut gs = Genestring::with_bits(values.len() as u64 * PIECE_SIZE_IN_BITS);
+            for (i, value) in values.iter().enumerate() {
+                gs.set(i as u64 * PIECE_SIZE_IN_BITS, PIECE_SIZE_IN_BITS, *value);
+            }
+
+            let encoded = gs.to_hex();
+            let back = Genestring::from_hex(&encoded).unwrap();
+            prop_assert_eq!(gs.pieces, back.pieces);
+        }
+
+        #[test]
+        fn gray_roundtrip(start in 0..192, bits in 1..32u32, value: u32) {
+            prop_assume!((start + bits as i32) < 256, "Value must be within bit string boundaries.");
+
+            let mut gs = Genestring::with_bits(256);
+
+            let mask = (1u64 << bits) - 1;
+            let masked_value = value as u64 & mask;
+
+            gs.set_gray(start as u64, bits as u64, masked_value);
+            prop_assert_eq!(gs.get_gray(start as u64, bits as u64), masked_value);
+        }
+
+        #[test]
+        fn gray_roundtrip_unmasked(start in 0..192, bits in 1..32u32, value: u32) {
+            // Unlike `gray_roundtrip`, `value` is passed to `set_gray` without being
+            // pre-masked, so this exercises `set_gray`'s own masking rather than relying on
+            // the caller to have done it already.
+            prop_assume!((start + bits as i32) < 256, "Value must be within bit string boundaries.");
+
+            let mut gs = Genestring::with_bits(256);
+
+            let mask = (1u64 << bits) - 1;
+            let masked_value = value as u64 & mask;
+
+            gs.set_gray(start as u64, bits as u64, value as u64);
+            prop_assert_eq!(gs.get_gray(start as u64, bits as u64), masked_value);
+        }
+
+        #[test]
+        fn gray_adjacent_values_differ_by_one_bit(bits in 1..20u32, value: u32) {
+            // Moving a Gray-coded field's value to an adjacent integer must only ever need a
+            // single mutated bit, so adjacent integers must encode to Gray codes that differ
+            // by exactly one stored bit.
+            let mask = (1u64 << bits) - 1;
+            let v = value as u64 & mask;
+            prop_assume!(v < mask, "Need room for an adjacent value within the field width.");
+
+            let g1 = gray_encode(v);
+            let g2 = gray_encode(v + 1);
+
+            prop_assert_eq!((g1 ^ g2).count_ones(), 1);
+        }
     }
 }
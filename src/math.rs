@@ -1,17 +1,272 @@
 use constants::*;
+use std::cmp::Ordering;
 
 /// Calculates the number of pieces required to hold this many bits in a genestring.
 pub fn part_count_for_bits(bits: u64) -> u64 {
     if bits == 0 {
         1
-    } else if bits % PIECE_SIZE_IN_BITS == 0 {
+    } else if bits.is_multiple_of(PIECE_SIZE_IN_BITS) {
         bits / PIECE_SIZE_IN_BITS
     } else {
         (bits / PIECE_SIZE_IN_BITS) + 1
     }
 }
 
+// Same as `part_count_for_bits`, but usable in const contexts (e.g. sizing the backing
+// array of a `FixedGenestring<N>`).
+pub const fn const_part_count_for_bits(bits: usize) -> usize {
+    let piece_bits = PIECE_SIZE_IN_BITS as usize;
+    if bits == 0 {
+        1
+    } else if bits.is_multiple_of(piece_bits) {
+        bits / piece_bits
+    } else {
+        (bits / piece_bits) + 1
+    }
+}
+
 // Calculates which piece contains a given bit.
 pub fn part_for_bit(bit: u64) -> u64 {
     bit / PIECE_SIZE_IN_BITS
 }
+
+// Closed-form mask of the bottom `bits` bits, branch-light and allocation-free in place of
+// the old `for i in 0..bits { acc += 1 << i; }` loop.
+pub(crate) fn bit_mask(bits: u64) -> u64 {
+    if bits == PIECE_SIZE_IN_BITS {
+        u64::MAX
+    } else {
+        (1u64 << bits) - 1
+    }
+}
+
+// Why a genestring's bit addressing can be out of bounds: either more than one piece's worth
+// of bits were requested at once, or the offset/width reaches past the end of the backing
+// store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutOfBounds {
+    TooManyBits,
+    OutOfRange,
+}
+
+// Retrieves `bits` number of bits from `pieces`, starting at a given `offset`. Shared by
+// every genestring backing store (`Vec`-backed and array-backed alike) so there is a single
+// implementation of the bit-addressing math. Callers are expected to have already checked
+// bounds.
+pub fn get_bits(pieces: &[u64], offset: u64, bits: u64) -> u64 {
+    if bits == 0 {
+        return 0;
+    }
+
+    let first_half_idx = part_for_bit(offset) as usize;
+    let second_half_idx = part_for_bit(offset + (bits - 1)) as usize;
+
+    let offset_modulo = offset % PIECE_SIZE_IN_BITS;
+
+    if first_half_idx != second_half_idx {
+        let p1_bits = PIECE_SIZE_IN_BITS - offset_modulo;
+        let p2_bits = bits - p1_bits;
+
+        let value_mask1 = bit_mask(p1_bits);
+        let piece_mask2 = bit_mask(p2_bits);
+        let piece_mask1 = value_mask1 << offset_modulo;
+
+        let mut result = (pieces[first_half_idx] & piece_mask1) >> offset_modulo;
+        result += (pieces[second_half_idx] & piece_mask2) << p1_bits;
+        result
+    } else {
+        let mask = bit_mask(bits) << offset_modulo;
+        (pieces[first_half_idx] & mask) >> offset_modulo
+    }
+}
+
+// Assigns bits at a given offset through offset+bits in `pieces` to the given value. The
+// writing counterpart to `get_bits`, shared by every backing store in the same way. Callers
+// are expected to have already checked bounds.
+pub fn set_bits(pieces: &mut [u64], offset: u64, bits: u64, value: u64) {
+    if bits == 0 {
+        return;
+    }
+
+    let first_half_idx = part_for_bit(offset) as usize;
+    let second_half_idx = part_for_bit(offset + (bits - 1)) as usize;
+
+    let offset_modulo = offset % PIECE_SIZE_IN_BITS;
+
+    if first_half_idx == second_half_idx {
+        // in this path, we are just writing to bits inside the same integer
+        let source_mask = bit_mask(bits);
+        let destination_mask = source_mask << offset_modulo;
+
+        pieces[first_half_idx] =
+            (pieces[first_half_idx] & !destination_mask) + ((value & source_mask) << offset_modulo);
+    } else {
+        let p1_bits = PIECE_SIZE_IN_BITS - offset_modulo;
+        let p2_bits = bits - p1_bits;
+
+        let value_mask1 = bit_mask(p1_bits);
+        let piece_mask2 = bit_mask(p2_bits);
+        let value_mask2 = piece_mask2 << p1_bits;
+        let piece_mask1 = value_mask1 << offset_modulo;
+
+        pieces[first_half_idx] =
+            (pieces[first_half_idx] & !piece_mask1) + ((value & value_mask1) << offset_modulo);
+        pieces[second_half_idx] =
+            (pieces[second_half_idx] & !piece_mask2) + ((value & value_mask2) >> p1_bits);
+    }
+}
+
+// Fallible counterpart to `get_bits`: validates `bits`/`offset` against `pieces` and returns a
+// typed error instead of the caller needing to check (and potentially panic on) bounds itself.
+pub fn try_get_bits(pieces: &[u64], offset: u64, bits: u64) -> Result<u64, OutOfBounds> {
+    if bits == 0 {
+        return Ok(0);
+    }
+
+    if bits > PIECE_SIZE_IN_BITS {
+        return Err(OutOfBounds::TooManyBits);
+    }
+
+    if bits + offset > pieces.len() as u64 * PIECE_SIZE_IN_BITS {
+        return Err(OutOfBounds::OutOfRange);
+    }
+
+    Ok(get_bits(pieces, offset, bits))
+}
+
+// Encodes `value` to its Gray code, where a single bit flip in the result corresponds to
+// moving one step to an adjacent integer: `g = b ^ (b >> 1)`.
+pub fn gray_encode(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+// Decodes a Gray-coded `value` back to binary via the standard prefix-XOR fold, masked down
+// to `bits` so that any bits above the field width don't leak into the result.
+pub fn gray_decode(value: u64, bits: u64) -> u64 {
+    let mut b = value;
+    b ^= b >> 1;
+    b ^= b >> 2;
+    b ^= b >> 4;
+    b ^= b >> 8;
+    b ^= b >> 16;
+    b ^= b >> 32;
+    b & bit_mask(bits)
+}
+
+// Fallible counterpart to `set_bits`.
+pub fn try_set_bits(pieces: &mut [u64], offset: u64, bits: u64, value: u64) -> Result<(), OutOfBounds> {
+    if bits == 0 {
+        return Ok(());
+    }
+
+    if bits > PIECE_SIZE_IN_BITS {
+        return Err(OutOfBounds::TooManyBits);
+    }
+
+    if bits + offset > pieces.len() as u64 * PIECE_SIZE_IN_BITS {
+        return Err(OutOfBounds::OutOfRange);
+    }
+
+    set_bits(pieces, offset, bits, value);
+    Ok(())
+}
+
+// Adds `a` and `b` as little-endian magnitudes (piece 0 holds the low bits) limb by limb into
+// `out`, propagating carry from each limb into the next. Operands shorter than `out` are
+// treated as having zero in their missing high limbs, so callers can add genestrings with
+// differing piece counts directly. Returns the carry bit out of the top of `out` instead of
+// panicking on overflow.
+pub fn overflowing_add(a: &[u64], b: &[u64], out: &mut [u64]) -> bool {
+    let mut carry = false;
+    for (i, o) in out.iter_mut().enumerate() {
+        let av = a.get(i).copied().unwrap_or(0);
+        let bv = b.get(i).copied().unwrap_or(0);
+        let (v, c1) = av.overflowing_add(bv);
+        let (v2, c2) = v.overflowing_add(carry as u64);
+        *o = v2;
+        carry = c1 | c2;
+    }
+    carry
+}
+
+// The subtraction counterpart to `overflowing_add`: `a - b`, limb by limb from the least
+// significant piece up, returning the final borrow instead of panicking on underflow. Missing
+// high limbs of either operand are treated as zero.
+pub fn overflowing_sub(a: &[u64], b: &[u64], out: &mut [u64]) -> bool {
+    let mut borrow = false;
+    for (i, o) in out.iter_mut().enumerate() {
+        let av = a.get(i).copied().unwrap_or(0);
+        let bv = b.get(i).copied().unwrap_or(0);
+        let (v, c1) = av.overflowing_sub(bv);
+        let (v2, c2) = v.overflowing_sub(borrow as u64);
+        *o = v2;
+        borrow = c1 | c2;
+    }
+    borrow
+}
+
+// Compares `a` and `b` as little-endian magnitudes, starting from the most significant limb
+// and working down. Missing high limbs (when the two slices differ in length) are treated as
+// zero.
+pub fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let av = a.get(i).copied().unwrap_or(0);
+        let bv = b.get(i).copied().unwrap_or(0);
+        match av.cmp(&bv) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+// Shifts the magnitude `a` left by `bits` into `out`, moving whole limbs first and then
+// fixing up the remainder bit shift with the complementary right-shift of the limb below.
+pub fn shl_limbs(a: &[u64], bits: u64, out: &mut [u64]) {
+    for o in out.iter_mut() {
+        *o = 0;
+    }
+
+    let limb_shift = (bits / PIECE_SIZE_IN_BITS) as usize;
+    let bit_shift = bits % PIECE_SIZE_IN_BITS;
+
+    for i in (0..a.len()).rev() {
+        let dest = i + limb_shift;
+        if dest >= out.len() {
+            continue;
+        }
+
+        let mut v = a[i] << bit_shift;
+        if bit_shift != 0 && i >= 1 {
+            v |= a[i - 1] >> (PIECE_SIZE_IN_BITS - bit_shift);
+        }
+        out[dest] = v;
+    }
+}
+
+// Shifts the magnitude `a` right by `bits` into `out`, moving whole limbs first and then
+// fixing up the remainder bit shift with the complementary left-shift of the limb above.
+pub fn shr_limbs(a: &[u64], bits: u64, out: &mut [u64]) {
+    for o in out.iter_mut() {
+        *o = 0;
+    }
+
+    let limb_shift = (bits / PIECE_SIZE_IN_BITS) as usize;
+    let bit_shift = bits % PIECE_SIZE_IN_BITS;
+
+    for i in limb_shift..a.len() {
+        let dest = i - limb_shift;
+        if dest >= out.len() {
+            continue;
+        }
+
+        let mut v = a[i] >> bit_shift;
+        if bit_shift != 0 {
+            if let Some(&hi) = a.get(i + 1) {
+                v |= hi << (PIECE_SIZE_IN_BITS - bit_shift);
+            }
+        }
+        out[dest] = v;
+    }
+}